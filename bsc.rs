@@ -0,0 +1,97 @@
+//! Binary symmetric channel simulation, driving the Hamming(8, 4) encode/decode pipeline
+//! to estimate post-decode bit-error rate.
+
+use crate::{hamming_decode, hamming_encode, ErrorType};
+
+/// Splitmix64 PRNG: a small, seedable generator good enough to drive a simulated channel
+/// and reproducible across runs given the same seed.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value uniformly distributed in [0, 1).
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+/// Flip each bit of `word` independently with probability `f` (a binary symmetric channel).
+fn corrupt(rng: &mut Rng, word: u8, f: f64) -> u8 {
+    let mut corrupted = word;
+    for bit in 0..8 {
+        if rng.next_f64() < f {
+            corrupted ^= 1 << bit;
+        }
+    }
+    corrupted
+}
+
+/// Count of each [`ErrorType`] the decoder reported while simulating a channel.
+#[derive(Debug, Default)]
+pub struct ErrorTypeCounts {
+    pub no_error: usize,
+    pub single_bit_error: usize,
+    pub parity_bit_error: usize,
+    pub double_bit_error: usize,
+}
+
+impl ErrorTypeCounts {
+    fn record(&mut self, error_type: &ErrorType) {
+        match error_type {
+            ErrorType::NoError => self.no_error += 1,
+            ErrorType::SingleBitError => self.single_bit_error += 1,
+            ErrorType::ParityBitError => self.parity_bit_error += 1,
+            ErrorType::DoubleBitError => self.double_bit_error += 1,
+        }
+    }
+}
+
+/// Result of driving `trials` random nibbles through a simulated noisy channel: the
+/// estimated post-decode bit-error rate, and a breakdown of how the decoder classified
+/// each trial.
+#[derive(Debug)]
+pub struct BerResult {
+    pub ber: f64,
+    pub error_types: ErrorTypeCounts,
+}
+
+/// Encode random nibbles, corrupt them over a binary symmetric channel that flips each
+/// transmitted bit independently with probability `f`, decode, and tally residual decode
+/// errors (bits in the decoded nibble that still differ from the original) against data
+/// bits sent (4 per trial), to estimate post-decode BER. `seed` makes the run reproducible.
+pub fn simulate_channel(f: f64, trials: usize, seed: u64) -> BerResult {
+    let mut rng = Rng::new(seed);
+    let mut error_types = ErrorTypeCounts::default();
+    let mut bit_errors: usize = 0;
+
+    for _ in 0..trials {
+        let nibble = (rng.next_u64() & 0xF) as u8;
+        let encoded = hamming_encode(nibble);
+        let corrupted = corrupt(&mut rng, encoded, f);
+        let (decoded, error_type) = hamming_decode(corrupted);
+        error_types.record(&error_type);
+        bit_errors += (decoded ^ nibble).count_ones() as usize;
+    }
+
+    BerResult {
+        ber: bit_errors as f64 / (trials * 4) as f64,
+        error_types,
+    }
+}
+
+/// Estimate the post-decode bit-error rate of Hamming(8, 4) over a binary symmetric
+/// channel with per-bit flip probability `f`, using `trials` random nibbles.
+pub fn estimate_ber(f: f64, trials: usize) -> f64 {
+    simulate_channel(f, trials, 0).ber
+}
@@ -0,0 +1,51 @@
+//! Erasure-aware decoding for channels that report which bit positions were lost rather
+//! than silently flipped.
+
+use crate::{ErrorType, H_8_4};
+
+/// Parity-check equations used to recover erasures: [`H_8_4`]'s three Hamming rows, plus
+/// the overall parity bit (bit 0) as a fourth equation over every codeword bit. The
+/// syndrome-based decoder in [`crate::hamming_error_correct`] treats the overall parity
+/// bit separately from `H_8_4`; erasure recovery needs it folded in as a genuine equation
+/// since an erased parity bit carries no information of its own otherwise.
+const PARITY_CHECKS: [u64; 4] = [H_8_4[0], H_8_4[1], H_8_4[2], 0xFF];
+
+/// Recover a Hamming(8, 4) codeword with known-erased bit positions. Since the code has
+/// minimum distance 4, up to 3 erasures are recoverable: treat each erased bit as unknown
+/// and restrict [`PARITY_CHECKS`] to the erased columns, then try every assignment of the
+/// erased bits and keep it only if exactly one satisfies every equation. If the erased
+/// columns aren't independent (more than one assignment fits, or more erasures than the
+/// four equations can resolve), returns `None`.
+///
+/// `erased` gives the 0-based bit positions (0 = parity bit, 7 = MSB) known to be lost;
+/// their value in `word` is ignored. The returned [`ErrorType`] is `NoError` if nothing
+/// needed recovering and `SingleBitError` if one or more erasures were filled in.
+pub fn decode_with_erasures(word: u8, erased: &[u8]) -> Option<(u8, ErrorType)> {
+    if erased.is_empty() {
+        return Some((word >> 4 & 0xF, ErrorType::NoError));
+    }
+
+    let known_mask: u8 = !erased.iter().fold(0u8, |mask, &bit| mask | 1 << bit);
+    let mut fit: Option<u8> = None;
+
+    for assignment in 0..1u16 << erased.len() {
+        let mut candidate = word & known_mask;
+        for (i, &bit) in erased.iter().enumerate() {
+            if assignment >> i & 1 == 1 {
+                candidate |= 1 << bit;
+            }
+        }
+
+        let satisfies_all = PARITY_CHECKS
+            .iter()
+            .all(|&row| (row & candidate as u64).count_ones().is_multiple_of(2));
+        if satisfies_all {
+            if fit.is_some() {
+                return None; // Erased columns aren't independent: more than one fit.
+            }
+            fit = Some(candidate);
+        }
+    }
+
+    fit.map(|word| (word >> 4 & 0xF, ErrorType::SingleBitError))
+}
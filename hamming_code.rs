@@ -1,11 +1,63 @@
+mod bsc;
+mod erasure;
+mod secded;
+mod slice_codec;
+
+/// Bit error types reported by a SECDED decode.
 #[derive(Debug, PartialEq)]
-enum ErrorType {
+pub enum ErrorType {
     NoError,
     SingleBitError,
     ParityBitError,
     DoubleBitError,
 }
 
+/// Outcome of a raw syndrome lookup against a parity-check matrix, before the overall
+/// parity bit is consulted to tell a corrected single-bit error apart from an
+/// uncorrectable double-bit one.
+pub(crate) enum Correction {
+    Clean,
+    Corrected(u64),
+    Uncorrectable,
+}
+
+/// Syndrome-decode `word` against parity-check matrix `h` (one row bitmask per syndrome
+/// bit, least significant row first) and `locs` (indexed by syndrome, mapping it to the
+/// codeword bitmask that corrects it; a zero entry means no known single-bit fix).
+///     s = sum over rows i of (popcount(row[i] & word) & 1) << i
+/// This is the arithmetic core shared by every Hamming/SECDED variant in this crate;
+/// everything specific to one code (codeword width, the extra overall parity bit used to
+/// tell single- from double-bit errors) lives in the caller. `word`/`h`/`locs` are `u64`
+/// so the same engine covers codewords up to 64 bits wide, e.g. [`secded::SecDed`].
+pub(crate) fn correct(word: u64, h: &[u64], locs: &[u64]) -> Correction {
+    let s = h
+        .iter()
+        .enumerate()
+        .fold(0u64, |acc, (i, &row)| acc | (((row & word).count_ones() & 1) as u64) << i);
+    if s == 0 {
+        Correction::Clean
+    } else {
+        let loc = locs[s as usize];
+        if loc != 0 {
+            Correction::Corrected(word ^ loc)
+        } else {
+            Correction::Uncorrectable
+        }
+    }
+}
+
+/// Parity-check matrix for Hamming(8, 4): row `i` is the bitmask of codeword bits
+/// contributing to syndrome bit `i`.
+///     x 7 6 5 4   3 2 1   s
+/// H = [ 0 1 1 1 | 1 0 0   2
+///       1 0 1 1 | 0 1 0   1
+///       1 1 0 1 | 0 0 1 ] 0
+pub(crate) const H_8_4: [u64; 3] = [0xD2, 0xB4, 0x78];
+
+/// Syndrome-to-bitmask map for Hamming(8, 4): `LOCS_8_4[s]` is the bit to flip to correct
+/// syndrome `s`, e.g. if `s == 4` then flip bit x3.
+const LOCS_8_4: [u64; 8] = [0, 0x02, 0x04, 0x80, 0x08, 0x40, 0x20, 0x10];
+
 /// Encode n in Hamming(8, 4).
 ///     d 3 2 1 0 h 2 1 0   d
 /// G = [ 1 0 0 0 | 0 1 1   3
@@ -15,7 +67,7 @@ enum ErrorType {
 /// x = nG: (d3 d2 d1 d0 h2 h1 h0)
 /// Returns:
 ///     x bits + parity bit i.e. d3 d2 d1 d0 h2 h1 h0 p
-fn hamming_encode(n: u8) -> u8 {
+pub fn hamming_encode(n: u8) -> u8 {
     // Data bits
     let d: [u8; 4] = [n >> 0 & 1, n >> 1 & 1, n >> 2 & 1, n >> 3 & 1];
 
@@ -28,60 +80,123 @@ fn hamming_encode(n: u8) -> u8 {
     d[3] << 7 | d[2] << 6 | d[1] << 5 | d[0] << 4 | h[2] << 3 | h[1] << 2 | h[0] << 1 | p
 }
 
-/// Error correct a Hamming(8, 4) encoded byte using H.
-///     x 7 6 5 4   3 2 1   s
-/// H = [ 0 1 1 1 | 1 0 0   0
-///       1 0 1 1 | 0 1 0   1
-///       1 1 0 1 | 0 0 1 ] 2
-/// s = Hx: (s0 s1 s2)^T, map s to incorrect bit position
+/// Error correct a Hamming(8, 4) encoded byte, via [`correct`] against [`H_8_4`]/[`LOCS_8_4`].
+/// The overall parity bit (bit 0) distinguishes a corrected single-bit error from an
+/// uncorrectable double-bit one: an odd total bit count means one (or three, ...) bits
+/// flipped since encoding, an even count means zero or two did.
 /// Returns: error corrected byte
-fn hamming_error_correct(x: u8) -> (u8, ErrorType) {
-    // Calculate syndrome bits using H
-    let s: [u8; 3] = [
-        (x >> 6 & 1) ^ (x >> 5 & 1) ^ (x >> 4 & 1) ^ (x >> 3 & 1),
-        (x >> 7 & 1) ^ (x >> 5 & 1) ^ (x >> 4 & 1) ^ (x >> 2 & 1),
-        (x >> 7 & 1) ^ (x >> 6 & 1) ^ (x >> 4 & 1) ^ (x >> 1 & 1),
-    ];
-
-    // Parse syndrome bits taking left bits to be more significant.
-    let syndrome: usize = (s[0] as usize) << 2 | (s[1] as usize) << 1 | (s[2] as usize) << 0;
-
-    let x_parity = (x >> 7 & 1)
-        ^ (x >> 6 & 1)
-        ^ (x >> 5 & 1)
-        ^ (x >> 4 & 1)
-        ^ (x >> 3 & 1)
-        ^ (x >> 2 & 1)
-        ^ (x >> 1 & 1);
-
-    let error_type;
-    if x & 1 != x_parity {
-        if syndrome > 0 {
-            error_type = ErrorType::SingleBitError;
-        } else {
-            return (x ^ 1, ErrorType::ParityBitError);
-        }
-    } else {
-        if syndrome == 0 {
-            return (x, ErrorType::NoError);
-        } else {
-            error_type = ErrorType::DoubleBitError;
-        }
+pub fn hamming_error_correct(x: u8) -> (u8, ErrorType) {
+    let odd_parity = x.count_ones() % 2 == 1;
+    match (odd_parity, correct(x as u64, &H_8_4, &LOCS_8_4)) {
+        (true, Correction::Clean) => (x ^ 1, ErrorType::ParityBitError),
+        (true, Correction::Corrected(word)) => (word as u8, ErrorType::SingleBitError),
+        (true, Correction::Uncorrectable) => unreachable!("H_8_4/LOCS_8_4 cover every nonzero syndrome"),
+        (false, Correction::Clean) => (x, ErrorType::NoError),
+        (false, _) => (x, ErrorType::DoubleBitError),
     }
-
-    // Other cases: single-bit or double-bit error
-    // Map syndrome to incorrect bit position, e.g. if syndrome = 4 then flip bit x3.
-    const SYNDROME_TO_BIT: [u8; 8] = [u8::MAX, 1, 2, 7, 3, 6, 5, 4];
-    let incorrect_bit = SYNDROME_TO_BIT[syndrome];
-    (x ^ 1 << incorrect_bit, error_type)
 }
 
 /// Returns: error-corrected data bits i.e. error-corrected x7 x6 x5 x4
-fn hamming_decode(x: u8) -> (u8, ErrorType) {
+pub fn hamming_decode(x: u8) -> (u8, ErrorType) {
     let (data, error_type) = hamming_error_correct(x);
     (data >> 4 & 0xF, error_type)
 }
 
+/// Parity-check matrix for Hamming(15, 11): row `i` is the bitmask of codeword bits
+/// (including check bit ci itself) contributing to syndrome bit `i`.
+const H_15_11: [u64; 4] = [0x0FE2, 0x71E4, 0xB668, 0xDAB0];
+
+/// Syndrome-to-bitmask map for Hamming(15, 11): `LOCS_15_11[s]` is the bit to flip to
+/// correct syndrome `s`. Syndromes 1, 2, 4, 8 point at one of the check bits c0..c3
+/// itself; the rest point at a data bit.
+const LOCS_15_11: [u64; 16] = [
+    0, 0x0002, 0x0004, 0x0100, 0x0008, 0x0400, 0x2000, 0x0040, 0x0010, 0x0800, 0x4000, 0x0080,
+    0x8000, 0x0200, 0x1000, 0x0020,
+];
+
+/// Encode 11 data bits in Hamming(15, 11) with an overall parity bit (SECDED over a
+/// 16-bit codeword). Four overlapping parity-check groups cover the data bits:
+///     c0 = d0^d1^d2^d3^d4^d5^d6
+///     c1 = d0^d1^d2^d3^d7^d8^d9
+///     c2 = d0^d1^d4^d5^d7^d8^d10
+///     c3 = d0^d2^d4^d6^d7^d9^d10
+/// Returns:
+///     d10 d9 d8 d7 d6 d5 d4 d3 d2 d1 d0 c3 c2 c1 c0 p
+pub fn encode15_11(data: u16) -> u16 {
+    let d: [u16; 11] = [
+        data >> 0 & 1,
+        data >> 1 & 1,
+        data >> 2 & 1,
+        data >> 3 & 1,
+        data >> 4 & 1,
+        data >> 5 & 1,
+        data >> 6 & 1,
+        data >> 7 & 1,
+        data >> 8 & 1,
+        data >> 9 & 1,
+        data >> 10 & 1,
+    ];
+
+    let c: [u16; 4] = [
+        d[0] ^ d[1] ^ d[2] ^ d[3] ^ d[4] ^ d[5] ^ d[6],
+        d[0] ^ d[1] ^ d[2] ^ d[3] ^ d[7] ^ d[8] ^ d[9],
+        d[0] ^ d[1] ^ d[4] ^ d[5] ^ d[7] ^ d[8] ^ d[10],
+        d[0] ^ d[2] ^ d[4] ^ d[6] ^ d[7] ^ d[9] ^ d[10],
+    ];
+
+    let p: u16 = d[10]
+        ^ d[9]
+        ^ d[8]
+        ^ d[7]
+        ^ d[6]
+        ^ d[5]
+        ^ d[4]
+        ^ d[3]
+        ^ d[2]
+        ^ d[1]
+        ^ d[0]
+        ^ c[3]
+        ^ c[2]
+        ^ c[1]
+        ^ c[0];
+
+    d[10] << 15
+        | d[9] << 14
+        | d[8] << 13
+        | d[7] << 12
+        | d[6] << 11
+        | d[5] << 10
+        | d[4] << 9
+        | d[3] << 8
+        | d[2] << 7
+        | d[1] << 6
+        | d[0] << 5
+        | c[3] << 4
+        | c[2] << 3
+        | c[1] << 2
+        | c[0] << 1
+        | p
+}
+
+/// Error correct and decode a Hamming(15, 11) encoded word, via [`correct`] against
+/// [`H_15_11`]/[`LOCS_15_11`]; same overall-parity-bit SECDED logic as
+/// [`hamming_error_correct`] distinguishes a corrected single-bit error from an
+/// uncorrectable double-bit one.
+/// Returns: error-corrected 11 data bits
+pub fn decode15_11(word: u16) -> (u16, ErrorType) {
+    let odd_parity = word.count_ones() % 2 == 1;
+    let (corrected, error_type) = match (odd_parity, correct(word as u64, &H_15_11, &LOCS_15_11)) {
+        (true, Correction::Clean) => (word ^ 1, ErrorType::ParityBitError),
+        (true, Correction::Corrected(w)) => (w as u16, ErrorType::SingleBitError),
+        (true, Correction::Uncorrectable) => {
+            unreachable!("H_15_11/LOCS_15_11 cover every nonzero syndrome")
+        }
+        (false, Correction::Clean) => (word, ErrorType::NoError),
+        (false, _) => (word, ErrorType::DoubleBitError),
+    };
+    (corrected >> 5 & 0x7FF, error_type)
+}
+
 fn main() {
     const N: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
     // Calculated by hand (x = nG)
@@ -112,9 +227,246 @@ fn main() {
     test_single_bit_or_parity_error(&x, &N);
     test_no_error(&x, &N);
     test_double_bit_error(&x);
+
+    test_no_error_15_11();
+    test_single_bit_or_parity_error_15_11();
+    test_double_bit_error_15_11();
+
+    test_bsc_no_noise();
+    sweep_bsc_ber();
+
+    test_slice_round_trip();
+    test_slice_odd_trailing_nibble();
+    test_slice_detects_errors();
+
+    test_erasure_recovery();
+    test_erasure_unrecoverable();
+
+    test_secded_matches_hamming_8_4();
+    test_secded_exhaustive(11);
+    test_secded_spot_check(26);
+    test_secded_spot_check(57);
+    test_secded_rejects_oversized_data_bits();
+
     println!("ALL TESTS PASSED!!!!!!!!");
 }
 
+fn test_secded_matches_hamming_8_4() {
+    // A generated 4-data-bit SECDED codec should land on the same (8, 4) shape as the
+    // hand-derived one: 3 Hamming parity bits plus 1 overall parity bit over 4 data bits.
+    let secded = secded::SecDed::new(4);
+    assert_eq!(secded.codeword_bits(), 8);
+    for n in 0..16u64 {
+        let word = secded.encode(n);
+        let (decoded, error_type) = secded.decode(word);
+        assert_eq!(decoded, n);
+        assert_eq!(error_type, ErrorType::NoError);
+    }
+    println!("Generated SECDED(4) round-trips like the hand-derived Hamming(8, 4) code.");
+}
+
+/// Exhaustively check every data value, every single-bit error and every double-bit error
+/// for a small `data_bits` width where that's still cheap (a few hundred thousand cases).
+fn test_secded_exhaustive(data_bits: usize) {
+    let secded = secded::SecDed::new(data_bits);
+    let max: u64 = (1 << data_bits) - 1;
+    let codeword_bits = secded.codeword_bits();
+
+    for data in 0..=max {
+        let word = secded.encode(data);
+        let (decoded, error_type) = secded.decode(word);
+        assert_eq!(decoded, data);
+        assert_eq!(error_type, ErrorType::NoError);
+
+        for bit in 0..codeword_bits {
+            let errored = word ^ (1 << bit);
+            let (decoded, error_type) = secded.decode(errored);
+            assert_eq!(decoded, data);
+            if bit == 0 {
+                assert_eq!(error_type, ErrorType::ParityBitError);
+            } else {
+                assert_eq!(error_type, ErrorType::SingleBitError);
+            }
+        }
+
+        for i in 0..codeword_bits - 1 {
+            for j in i + 1..codeword_bits {
+                let errored = word ^ (1 << i) ^ (1 << j);
+                let (_, error_type) = secded.decode(errored);
+                assert_eq!(error_type, ErrorType::DoubleBitError);
+            }
+        }
+    }
+    println!("Generated SECDED({data_bits}) exhaustively passes no-error/single-bit/double-bit checks.");
+}
+
+/// Spot-check a handful of values for a `data_bits` width too wide to exhaust exhaustively.
+fn test_secded_spot_check(data_bits: usize) {
+    let secded = secded::SecDed::new(data_bits);
+    let max: u64 = (1 << data_bits) - 1;
+    let codeword_bits = secded.codeword_bits();
+
+    for data in [0, 1, max / 2, max - 1, max] {
+        let word = secded.encode(data);
+        let (decoded, error_type) = secded.decode(word);
+        assert_eq!(decoded, data);
+        assert_eq!(error_type, ErrorType::NoError);
+
+        for bit in 0..codeword_bits {
+            let errored = word ^ (1 << bit);
+            let (decoded, error_type) = secded.decode(errored);
+            assert_eq!(decoded, data);
+            if bit == 0 {
+                assert_eq!(error_type, ErrorType::ParityBitError);
+            } else {
+                assert_eq!(error_type, ErrorType::SingleBitError);
+            }
+        }
+    }
+    println!("Generated SECDED({data_bits}) spot-checked for no-error/single-bit correction.");
+}
+
+fn test_secded_rejects_oversized_data_bits() {
+    // 57 is the largest width whose generated positions still fit a u64 codeword; one more
+    // must be rejected rather than silently wrapping into a corrupted codec.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(|| secded::SecDed::new(58));
+    std::panic::set_hook(hook);
+    assert!(result.is_err(), "SecDed::new(58) should panic: 58 data bits overflow a u64 codeword");
+    println!("Generated SECDED construction rejects data widths past 57 bits.");
+}
+
+fn test_erasure_recovery() {
+    for n in 0..16 {
+        let word = hamming_encode(n);
+        // Every single erasure, and every pair, is always recoverable.
+        for bit in 0..8 {
+            let recovered = erasure::decode_with_erasures(word, &[bit]);
+            assert_eq!(recovered, Some((n, ErrorType::SingleBitError)));
+        }
+        for bit1 in 0..8u8 {
+            for bit2 in bit1 + 1..8u8 {
+                let recovered = erasure::decode_with_erasures(word, &[bit1, bit2]);
+                assert_eq!(recovered, Some((n, ErrorType::SingleBitError)));
+            }
+        }
+    }
+    assert_eq!(
+        erasure::decode_with_erasures(hamming_encode(5), &[]),
+        Some((5, ErrorType::NoError))
+    );
+    println!("Erasure recovery fills in known-lost bits up to 2 at a time.");
+}
+
+fn test_erasure_unrecoverable() {
+    // All 8 bit positions erased: no information left to pin down a unique codeword.
+    let word = hamming_encode(7);
+    let erased: [u8; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+    assert_eq!(erasure::decode_with_erasures(word, &erased), None);
+    println!("Erasure recovery reports None when the erased columns aren't independent.");
+}
+
+fn test_slice_round_trip() {
+    let data: [u8; 5] = [0x12, 0x34, 0x56, 0x78, 0x9A];
+    let encoded = slice_codec::encode_slice(&data);
+    assert_eq!(encoded.len(), data.len() * 2);
+    let (decoded, error_types) = slice_codec::decode_slice(&encoded);
+    assert_eq!(decoded, data);
+    assert!(error_types.iter().all(|e| *e == ErrorType::NoError));
+    println!("Slice codec round-trips byte buffers with no errors.");
+}
+
+fn test_slice_odd_trailing_nibble() {
+    let data: [u8; 3] = [0xAB, 0xCD, 0xEF];
+    let mut encoded = slice_codec::encode_slice(&data);
+    encoded.pop(); // Drop the last codeword, leaving an unpaired trailing nibble.
+    let (decoded, error_types) = slice_codec::decode_slice(&encoded);
+    assert_eq!(decoded.len(), data.len());
+    assert_eq!(decoded[..2], data[..2]);
+    assert_eq!(decoded[2], data[2] & 0xF); // High nibble is zeroed, low nibble recovered.
+    assert_eq!(error_types.len(), 5);
+    println!("Slice codec handles an odd trailing nibble.");
+}
+
+fn test_slice_detects_errors() {
+    let data: [u8; 2] = [0x5A, 0xA5];
+    let mut encoded = slice_codec::encode_slice(&data);
+    encoded[0] ^= 1 << 3; // Single-bit error in the first codeword.
+    encoded[2] ^= 0b11; // Double-bit error in the third codeword.
+    let (_, error_types) = slice_codec::decode_slice(&encoded);
+    assert_eq!(error_types[0], ErrorType::SingleBitError);
+    assert_eq!(error_types[2], ErrorType::DoubleBitError);
+    println!("Slice codec reports per-codeword error classification.");
+}
+
+fn test_bsc_no_noise() {
+    assert_eq!(bsc::estimate_ber(0.0, 1000), 0.0);
+    let result = bsc::simulate_channel(0.0, 1000, 0);
+    assert_eq!(result.error_types.no_error, 1000);
+    println!("Binary symmetric channel with f = 0 introduces no residual errors.");
+}
+
+fn sweep_bsc_ber() {
+    const TRIALS: usize = 100_000;
+    println!("Hamming(8, 4) post-decode BER over a binary symmetric channel:");
+    let mut f = 0.0;
+    while f <= 0.5 {
+        let result = bsc::simulate_channel(f, TRIALS, 0);
+        println!(
+            "  f = {:.2}: ber = {:.5}, no_error = {}, single_bit = {}, parity_bit = {}, double_bit = {}",
+            f,
+            result.ber,
+            result.error_types.no_error,
+            result.error_types.single_bit_error,
+            result.error_types.parity_bit_error,
+            result.error_types.double_bit_error,
+        );
+        f += 0.05;
+    }
+}
+
+fn test_no_error_15_11() {
+    for data in 0..=0x7FF {
+        let word = encode15_11(data);
+        let decoded = decode15_11(word);
+        assert_eq!(decoded.0, data);
+        assert_eq!(decoded.1, ErrorType::NoError);
+    }
+    println!("Hamming(15, 11): data with no errors successfully decoded.");
+}
+
+fn test_single_bit_or_parity_error_15_11() {
+    for data in 0..=0x7FF {
+        let word = encode15_11(data);
+        for bit in 0..16 {
+            let errored = word ^ 1 << bit;
+            let decoded = decode15_11(errored);
+            assert_eq!(decoded.0, data);
+            if bit == 0 {
+                assert_eq!(decoded.1, ErrorType::ParityBitError);
+            } else {
+                assert_eq!(decoded.1, ErrorType::SingleBitError);
+            }
+        }
+    }
+    println!("Hamming(15, 11): single-bit/parity-bit errors were successfully error corrected.");
+}
+
+fn test_double_bit_error_15_11() {
+    for data in 0..=0x7FF {
+        let word = encode15_11(data);
+        for i in 0..15 {
+            for j in i + 1..16 {
+                let errored = word ^ 1 << i ^ 1 << j;
+                let decoded = decode15_11(errored);
+                assert_eq!(decoded.1, ErrorType::DoubleBitError);
+            }
+        }
+    }
+    println!("Hamming(15, 11): double-bit errors were successfully detected.");
+}
+
 fn test_double_bit_error(x: &[u8]) {
     let mut count;
     let mut errored;
@@ -0,0 +1,112 @@
+//! Auto-sized SECDED construction for arbitrary data widths, generating the Hamming
+//! parity structure programmatically instead of hand-deriving G/H matrices per width.
+
+use crate::{correct, Correction, ErrorType};
+
+/// A generated Hamming SECDED codec for a fixed number of data bits (up to 57, so the
+/// codeword plus overall parity bit fits in a `u64`).
+///
+/// Codeword positions are numbered 1-based following the standard Hamming scheme: a
+/// position that is a power of two is a parity bit, covering every position whose binary
+/// representation has that power-of-two's bit set. Of the other positions up to the
+/// smallest `width = 2^parity_bits - 1` that fits the data, the first `data_bits` in
+/// increasing position order are data bits; any left over are unused and encode as zero.
+/// An overall parity bit is appended at bit 0, exactly as the
+/// (8, 4) and (15, 11) codecs in this crate, to tell a corrected single-bit error apart
+/// from an uncorrectable double-bit one. `h`/`locs` are the generated parity-check matrix
+/// and syndrome-to-bitmask table, precomputed once in [`SecDed::new`] and consulted in
+/// O(1) by [`crate::correct`], mirroring the [`crate::H_8_4`]/[`crate::LOCS_8_4`] and
+/// [`crate::H_15_11`]/[`crate::LOCS_15_11`] const tables for the fixed-width codes.
+pub struct SecDed {
+    parity_bits: u32,
+    data_positions: Vec<u32>,
+    h: Vec<u64>,
+    locs: Vec<u64>,
+}
+
+impl SecDed {
+    /// Build a SECDED codec for `data_bits` data bits, generating the minimum number of
+    /// parity bits `m` such that `2^m >= data_bits + m + 1`.
+    ///
+    /// Panics if `data_bits` exceeds 57: beyond that the generated positions no longer fit
+    /// a `u64` codeword (`1 << pos` would shift out of range), so this is rejected up front
+    /// rather than silently producing a corrupted codec.
+    pub fn new(data_bits: usize) -> Self {
+        assert!(
+            data_bits <= 57,
+            "SecDed supports at most 57 data bits (codeword plus overall parity bit must fit a u64), got {data_bits}"
+        );
+
+        let mut parity_bits = 0u32;
+        while (1usize << parity_bits) < data_bits + parity_bits as usize + 1 {
+            parity_bits += 1;
+        }
+        let width = (1u32 << parity_bits) - 1;
+        let data_positions: Vec<u32> = (1..=width)
+            .filter(|pos| pos.count_ones() >= 2)
+            .take(data_bits)
+            .collect();
+
+        // Row k covers every position whose binary representation has bit k set, i.e. the
+        // group that parity bit 2^k checks.
+        let h: Vec<u64> = (0..parity_bits)
+            .map(|k| {
+                (1..=width)
+                    .filter(|pos| pos & (1 << k) != 0)
+                    .fold(0u64, |row, pos| row | 1 << pos)
+            })
+            .collect();
+
+        // In this canonical (interleaved) position numbering, a position's column in `h`
+        // equals the position's own numeric value, so the syndrome for a single-bit error
+        // at position `s` is `s` itself: `locs[s] = 1 << s`.
+        let locs: Vec<u64> = (0..=width).map(|s| if s == 0 { 0 } else { 1u64 << s }).collect();
+
+        SecDed { parity_bits, data_positions, h, locs }
+    }
+
+    /// Total codeword width in bits, including the overall parity bit at bit 0.
+    pub fn codeword_bits(&self) -> u32 {
+        1 << self.parity_bits
+    }
+
+    /// Encode `data` (only the low `data_bits` bits are used) into a codeword: data bits
+    /// placed at their assigned positions, Hamming parity bits filled in using the rows of
+    /// `h`, and an overall parity bit appended at bit 0.
+    pub fn encode(&self, data: u64) -> u64 {
+        let mut word = 0u64;
+        for (i, &pos) in self.data_positions.iter().enumerate() {
+            if data >> i & 1 == 1 {
+                word |= 1 << pos;
+            }
+        }
+        for (k, &row) in self.h.iter().enumerate() {
+            let parity = (row & word).count_ones() & 1;
+            word |= (parity as u64) << (1 << k);
+        }
+        word | (word.count_ones() % 2) as u64
+    }
+
+    /// Error correct and decode a SECDED-encoded word: syndrome-decode against `h`/`locs`
+    /// via [`crate::correct`], then use the overall parity bit (bit 0) to tell a single-bit
+    /// error apart from an uncorrectable double-bit one, exactly as
+    /// [`crate::hamming_error_correct`] and [`crate::decode15_11`] do.
+    /// Returns: error-corrected data bits
+    pub fn decode(&self, word: u64) -> (u64, ErrorType) {
+        let odd_parity = word.count_ones() % 2 == 1;
+        let (corrected, error_type) = match (odd_parity, correct(word, &self.h, &self.locs)) {
+            (true, Correction::Clean) => (word ^ 1, ErrorType::ParityBitError),
+            (true, Correction::Corrected(w)) => (w, ErrorType::SingleBitError),
+            (true, Correction::Uncorrectable) => unreachable!("h/locs cover every nonzero syndrome"),
+            (false, Correction::Clean) => (word, ErrorType::NoError),
+            (false, _) => (word, ErrorType::DoubleBitError),
+        };
+
+        let data = self
+            .data_positions
+            .iter()
+            .enumerate()
+            .fold(0u64, |data, (i, &pos)| data | ((corrected >> pos & 1) << i));
+        (data, error_type)
+    }
+}
@@ -0,0 +1,45 @@
+//! Hamming(8, 4) codec over arbitrary byte buffers, for use as a forward-error-correction
+//! layer over byte data rather than single values.
+
+use crate::{hamming_decode, hamming_encode, ErrorType};
+
+/// Split each byte of `data` into a low and high nibble and Hamming-encode each nibble
+/// into its own protected byte (low nibble first), so `encode_slice(data).len() ==
+/// data.len() * 2`.
+pub fn encode_slice(data: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(data.len() * 2);
+    for &byte in data {
+        encoded.push(hamming_encode(byte & 0xF));
+        encoded.push(hamming_encode(byte >> 4 & 0xF));
+    }
+    encoded
+}
+
+/// Error-correct and decode each protected byte in `encoded`, reassembling nibble pairs
+/// (low nibble first) back into bytes. Returns the decoded data alongside the per-codeword
+/// error classification, so callers can tally e.g. single-bit corrections or
+/// uncorrectable double-bit detections to decide whether to request retransmission.
+///
+/// If `encoded` has a trailing nibble with no partner (an odd number of codewords), it is
+/// decoded and placed in the low nibble of one final byte with the high nibble zeroed.
+pub fn decode_slice(encoded: &[u8]) -> (Vec<u8>, Vec<ErrorType>) {
+    let mut data = Vec::with_capacity(encoded.len().div_ceil(2));
+    let mut error_types = Vec::with_capacity(encoded.len());
+
+    let mut pairs = encoded.chunks_exact(2);
+    for pair in &mut pairs {
+        let (lo, lo_error) = hamming_decode(pair[0]);
+        let (hi, hi_error) = hamming_decode(pair[1]);
+        data.push(hi << 4 | lo);
+        error_types.push(lo_error);
+        error_types.push(hi_error);
+    }
+
+    if let [lo] = pairs.remainder() {
+        let (lo, lo_error) = hamming_decode(*lo);
+        data.push(lo);
+        error_types.push(lo_error);
+    }
+
+    (data, error_types)
+}